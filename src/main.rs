@@ -1,31 +1,95 @@
-use chrono::{TimeZone, Utc};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Locale, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use clap::Parser;
 
-/// Determines the datetime format string based on the format option
+/// Determines the strftime format string based on the format option.
+///
+/// `"rfc2822"` and `"rfc3339"` are handled separately (rendered in
+/// `format_datetime` via chrono's dedicated `to_rfc2822`/`to_rfc3339` methods,
+/// parsed in `main` via `parse_from_rfc2822`/`parse_from_rfc3339`) and never
+/// reach this function.
+/// `"jpwd"` reaches this function for rendering, but is parsed back via
+/// `parse_jpwd_date` rather than `parse_naive_datetime`.
 fn get_format_string(format_option: Option<&str>) -> &str {
     match format_option {
         None => "%Y-%m-%d",                   // Default: date only
         Some("iso") => "%Y-%m-%dT%H:%M:%S%z", // ISO8601
         Some("jp") => "%Y年%m月%d日",
-        Some("jpwd") => "%Y年%m月%d日(%w)", // Japanese date with weekday placeholder
+        Some("jpwd") => "%Y年%m月%d日(%a)", // Japanese date with localized weekday
         Some("jphm") => "%Y年%m月%d日 %H時%M分",
         Some("jphms") => "%Y年%m月%d日 %H時%M分%S秒",
         Some(fmt) => fmt, // Custom format
     }
 }
 
-/// Converts a weekday number to Japanese character
-/// 0 = Sunday (日), 1 = Monday (月), etc.
-fn get_japanese_weekday(weekday_num: char) -> &'static str {
-    match weekday_num {
-        '0' => "日",
-        '1' => "月",
-        '2' => "火",
-        '3' => "水",
-        '4' => "木",
-        '5' => "金",
-        '6' => "土",
-        _ => "?",
+/// Validates that `format_str` contains no unrecognized strftime specifiers.
+///
+/// chrono only surfaces a bad specifier as an `Item::Error` at render time,
+/// which otherwise panics via `Display` inside `.format()`. Checking the
+/// parsed items up front lets us fail cleanly instead.
+fn validate_format_string(format_str: &str) -> Result<(), String> {
+    if StrftimeItems::new(format_str).any(|item| item == Item::Error) {
+        return Err(format!(
+            "Invalid format string '{}': contains an unrecognized format specifier",
+            format_str
+        ));
+    }
+    Ok(())
+}
+
+/// Renders `datetime` using `format_option`, producing the final output string.
+///
+/// `"rfc2822"` and `"rfc3339"` bypass strftime entirely via chrono's dedicated
+/// methods; everything else is validated and rendered through `format_localized`.
+fn format_datetime(
+    datetime: DateTime<chrono_tz::Tz>,
+    format_option: Option<&str>,
+    locale: Locale,
+) -> Result<String, String> {
+    match format_option {
+        Some("rfc2822") => Ok(datetime.to_rfc2822()),
+        Some("rfc3339") => Ok(datetime.to_rfc3339()),
+        _ => {
+            let format_str = get_format_string(format_option);
+            validate_format_string(format_str)?;
+            Ok(datetime.format_localized(format_str, locale).to_string())
+        }
+    }
+}
+
+/// Resolves a `-l/--locale` value (e.g. `"ja_JP"`, `"en_US"`, `"de_DE"`) into a
+/// `chrono::Locale` used to render localized `%A`/`%a`/`%B`/`%b` names.
+fn resolve_locale(locale: &str) -> Result<Locale, String> {
+    match locale {
+        "ja_JP" => Ok(Locale::ja_JP),
+        "en_US" => Ok(Locale::en_US),
+        "de_DE" => Ok(Locale::de_DE),
+        "fr_FR" => Ok(Locale::fr_FR),
+        "es_ES" => Ok(Locale::es_ES),
+        "zh_CN" => Ok(Locale::zh_CN),
+        "ko_KR" => Ok(Locale::ko_KR),
+        _ => Err(format!(
+            "Invalid locale '{}'. Supported locales: ja_JP, en_US, de_DE, fr_FR, es_ES, zh_CN, ko_KR.",
+            locale
+        )),
+    }
+}
+
+/// Resolves a timezone name into a `chrono_tz::Tz`.
+///
+/// `"UTC"` and `"JST"` are kept as convenience aliases for `UTC` and
+/// `Asia/Tokyo`; anything else is looked up as a full IANA/Olson identifier
+/// (e.g. `"America/New_York"`, `"Europe/London"`).
+fn resolve_timezone(timezone: &str) -> Result<chrono_tz::Tz, String> {
+    match timezone {
+        "UTC" => Ok(chrono_tz::UTC),
+        "JST" => Ok(chrono_tz::Asia::Tokyo),
+        name => name.parse::<chrono_tz::Tz>().map_err(|_| {
+            format!(
+                "Invalid timezone '{}'. Use an IANA identifier such as 'Asia/Tokyo' or 'America/New_York'.",
+                name
+            )
+        }),
     }
 }
 
@@ -43,7 +107,7 @@ mod tests {
 
         // Test Japanese formats
         assert_eq!(get_format_string(Some("jp")), "%Y年%m月%d日");
-        assert_eq!(get_format_string(Some("jpwd")), "%Y年%m月%d日(%w)");
+        assert_eq!(get_format_string(Some("jpwd")), "%Y年%m月%d日(%a)");
         assert_eq!(get_format_string(Some("jphm")), "%Y年%m月%d日 %H時%M分");
         assert_eq!(
             get_format_string(Some("jphms")),
@@ -58,125 +122,397 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_get_japanese_weekday() {
-        assert_eq!(get_japanese_weekday('0'), "日");
-        assert_eq!(get_japanese_weekday('1'), "月");
-        assert_eq!(get_japanese_weekday('2'), "火");
-        assert_eq!(get_japanese_weekday('3'), "水");
-        assert_eq!(get_japanese_weekday('4'), "木");
-        assert_eq!(get_japanese_weekday('5'), "金");
-        assert_eq!(get_japanese_weekday('6'), "土");
-        assert_eq!(get_japanese_weekday('9'), "?");
-    }
-
     #[test]
     fn test_parse_time_adjustment() {
         use chrono::Duration;
 
+        let adj = |months, duration| TimeAdjustment { months, duration };
+
         // Test positive adjustments
-        assert_eq!(parse_time_adjustment("30s").unwrap(), Duration::seconds(30));
-        assert_eq!(parse_time_adjustment("5m").unwrap(), Duration::minutes(5));
-        assert_eq!(parse_time_adjustment("2h").unwrap(), Duration::hours(2));
-        assert_eq!(parse_time_adjustment("1d").unwrap(), Duration::days(1));
-        assert_eq!(parse_time_adjustment("3w").unwrap(), Duration::weeks(3));
+        assert_eq!(
+            parse_time_adjustment("30s").unwrap(),
+            adj(0, Duration::seconds(30))
+        );
+        assert_eq!(
+            parse_time_adjustment("5m").unwrap(),
+            adj(0, Duration::minutes(5))
+        );
+        assert_eq!(
+            parse_time_adjustment("2h").unwrap(),
+            adj(0, Duration::hours(2))
+        );
+        assert_eq!(
+            parse_time_adjustment("1d").unwrap(),
+            adj(0, Duration::days(1))
+        );
+        assert_eq!(
+            parse_time_adjustment("3w").unwrap(),
+            adj(0, Duration::weeks(3))
+        );
 
         // Test with explicit plus sign
         assert_eq!(
             parse_time_adjustment("+45s").unwrap(),
-            Duration::seconds(45)
+            adj(0, Duration::seconds(45))
         );
         assert_eq!(
             parse_time_adjustment("+10m").unwrap(),
-            Duration::minutes(10)
+            adj(0, Duration::minutes(10))
         );
 
         // Test negative adjustments
         assert_eq!(
             parse_time_adjustment("-15s").unwrap(),
-            Duration::seconds(-15)
+            adj(0, Duration::seconds(-15))
+        );
+        assert_eq!(
+            parse_time_adjustment("-3m").unwrap(),
+            adj(0, Duration::minutes(-3))
+        );
+        assert_eq!(
+            parse_time_adjustment("-1h").unwrap(),
+            adj(0, Duration::hours(-1))
+        );
+        assert_eq!(
+            parse_time_adjustment("-2d").unwrap(),
+            adj(0, Duration::days(-2))
+        );
+        assert_eq!(
+            parse_time_adjustment("-1w").unwrap(),
+            adj(0, Duration::weeks(-1))
+        );
+
+        // Test calendar units
+        assert_eq!(parse_time_adjustment("1M").unwrap(), adj(1, Duration::zero()));
+        assert_eq!(parse_time_adjustment("2y").unwrap(), adj(24, Duration::zero()));
+        assert_eq!(parse_time_adjustment("-1y").unwrap(), adj(-12, Duration::zero()));
+
+        // Test chained/compound adjustments
+        assert_eq!(
+            parse_time_adjustment("1d12h30m").unwrap(),
+            adj(0, Duration::days(1) + Duration::hours(12) + Duration::minutes(30))
+        );
+        assert_eq!(
+            parse_time_adjustment("-1y2M15d").unwrap(),
+            adj(-14, Duration::days(-15))
         );
-        assert_eq!(parse_time_adjustment("-3m").unwrap(), Duration::minutes(-3));
-        assert_eq!(parse_time_adjustment("-1h").unwrap(), Duration::hours(-1));
-        assert_eq!(parse_time_adjustment("-2d").unwrap(), Duration::days(-2));
-        assert_eq!(parse_time_adjustment("-1w").unwrap(), Duration::weeks(-1));
 
         // Test error cases
         assert!(parse_time_adjustment("").is_err()); // Empty string
         assert!(parse_time_adjustment("s").is_err()); // Missing numeric part
         assert!(parse_time_adjustment("10").is_err()); // Missing unit
         assert!(parse_time_adjustment("10x").is_err()); // Invalid unit
+        assert!(parse_time_adjustment("99999999999y").is_err()); // Year count overflows i32
+        assert!(parse_time_adjustment("3000000000M").is_err()); // Month count overflows i32
     }
+
+    #[test]
+    fn test_parse_naive_datetime() {
+        let expected = NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(
+            parse_naive_datetime("2024-03-01", "%Y-%m-%d").unwrap(),
+            expected
+        );
+
+        let expected_with_time = NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        assert_eq!(
+            parse_naive_datetime("2024-03-01 09:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            expected_with_time
+        );
+
+        assert!(parse_naive_datetime("not-a-date", "%Y-%m-%d").is_err());
+    }
+
+    #[test]
+    fn test_parse_jpwd_date() {
+        let expected = NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(parse_jpwd_date("2024年03月01日(金)").unwrap(), expected);
+        assert!(parse_jpwd_date("2024年03月01日").is_err()); // Missing "(weekday)"
+        assert!(parse_jpwd_date("garbage(金)").is_err());
+    }
+
+    #[test]
+    fn test_resolve_timezone() {
+        assert_eq!(resolve_timezone("UTC").unwrap(), chrono_tz::UTC);
+        assert_eq!(resolve_timezone("JST").unwrap(), chrono_tz::Asia::Tokyo);
+        assert_eq!(resolve_timezone("Asia/Tokyo").unwrap(), chrono_tz::Asia::Tokyo);
+        assert_eq!(
+            resolve_timezone("America/New_York").unwrap(),
+            chrono_tz::America::New_York
+        );
+        assert!(resolve_timezone("Not/AZone").is_err());
+    }
+
+    #[test]
+    fn test_resolve_locale() {
+        assert_eq!(resolve_locale("ja_JP").unwrap(), Locale::ja_JP);
+        assert_eq!(resolve_locale("en_US").unwrap(), Locale::en_US);
+        assert_eq!(resolve_locale("de_DE").unwrap(), Locale::de_DE);
+        assert!(resolve_locale("xx_XX").is_err());
+    }
+
+    #[test]
+    fn test_format_datetime() {
+        let dt = chrono_tz::UTC.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap();
+
+        assert_eq!(
+            format_datetime(dt, Some("rfc2822"), Locale::en_US).unwrap(),
+            dt.to_rfc2822()
+        );
+        assert_eq!(
+            format_datetime(dt, Some("rfc3339"), Locale::en_US).unwrap(),
+            dt.to_rfc3339()
+        );
+        assert_eq!(
+            format_datetime(dt, Some("%Y/%m/%d"), Locale::en_US).unwrap(),
+            "2024/03/01"
+        );
+
+        // An unrecognized specifier must return Err, not panic via Display.
+        assert!(format_datetime(dt, Some("%Q"), Locale::en_US).is_err());
+    }
+
+    #[test]
+    fn test_validate_format_string() {
+        assert!(validate_format_string("%Y-%m-%d").is_ok());
+        assert!(validate_format_string("%Y年%m月%d日(%a)").is_ok());
+        assert!(validate_format_string("%Q").is_err()); // Unknown specifier
+    }
+}
+
+/// Parse a formatted date string back into a `NaiveDateTime`, using `format_str`.
+///
+/// Falls back to parsing as a bare date (midnight) when `format_str` has no
+/// time fields, so presets like `jp` can still be round-tripped.
+fn parse_naive_datetime(date_str: &str, format_str: &str) -> Result<NaiveDateTime, String> {
+    if let Ok(dt) = NaiveDateTime::parse_from_str(date_str, format_str) {
+        return Ok(dt);
+    }
+
+    NaiveDate::parse_from_str(date_str, format_str)
+        .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
+        .map_err(|e| format!("Failed to parse '{}' with format '{}': {}", date_str, format_str, e))
 }
 
-/// Parse a time adjustment string like "1m", "-30s", "2d"
-fn parse_time_adjustment(adj: &str) -> Result<chrono::Duration, String> {
+/// Parse a `jpwd`-formatted date string (`"%Y年%m月%d日(%a)"`) back into a `NaiveDateTime`.
+///
+/// `%a` has no locale-aware parsing counterpart to `format_localized`, so the
+/// parenthesized weekday can't be fed back into `NaiveDate::parse_from_str`
+/// directly. Instead, the weekday is stripped off and the date part is parsed
+/// on its own, mirroring how `rfc2822`/`rfc3339` get a dedicated parse path in
+/// `main` instead of going through `get_format_string`.
+fn parse_jpwd_date(date_str: &str) -> Result<NaiveDateTime, String> {
+    let open_paren = date_str
+        .rfind('(')
+        .ok_or_else(|| format!("Failed to parse '{}' as jpwd: missing '(weekday)'", date_str))?;
+
+    NaiveDate::parse_from_str(&date_str[..open_paren], "%Y年%m月%d日")
+        .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
+        .map_err(|e| format!("Failed to parse '{}' as jpwd: {}", date_str, e))
+}
+
+/// A parsed time adjustment: a calendar-aware month count plus a fixed duration.
+///
+/// Months/years can't be represented as a fixed `chrono::Duration` (their
+/// length depends on the date they're applied from), so they're tracked
+/// separately and applied via calendar arithmetic in `main`.
+#[derive(Debug, PartialEq)]
+struct TimeAdjustment {
+    months: i32,
+    duration: chrono::Duration,
+}
+
+/// Parse a (possibly compound) time adjustment string like "1m", "-30s", "2d",
+/// "1d12h30m", or "-1y2M15d".
+///
+/// A single leading `+`/`-` applies to the whole expression. Supported units:
+/// s (seconds), m (minutes), h (hours), d (days), w (weeks), M (months), y (years).
+fn parse_time_adjustment(adj: &str) -> Result<TimeAdjustment, String> {
     if adj.is_empty() {
         return Err("Empty time adjustment string".to_string());
     }
 
-    // Check if it's a negative adjustment
-    let (is_negative, adj_str) = if adj.starts_with('-') {
-        (true, &adj[1..])
-    } else if adj.starts_with('+') {
-        (false, &adj[1..])
+    let (is_negative, adj_str) = if let Some(rest) = adj.strip_prefix('-') {
+        (true, rest)
+    } else if let Some(rest) = adj.strip_prefix('+') {
+        (false, rest)
     } else {
         (false, adj)
     };
 
-    // Parse the numeric part and unit
-    let mut numeric_part = String::new();
-    let mut unit_part = String::new();
+    if adj_str.is_empty() {
+        return Err(format!("Missing numeric part in '{}'", adj));
+    }
 
-    for c in adj_str.chars() {
-        if c.is_digit(10) {
-            numeric_part.push(c);
-        } else {
-            unit_part.push(c);
+    let mut months = 0i32;
+    let mut duration = chrono::Duration::zero();
+    let mut chars = adj_str.chars().peekable();
+
+    while chars.peek().is_some() {
+        let mut numeric_part = String::new();
+        while chars.peek().is_some_and(|c| c.is_ascii_digit()) {
+            numeric_part.push(chars.next().unwrap());
+        }
+        if numeric_part.is_empty() {
+            return Err(format!("Missing numeric part in '{}'", adj));
+        }
+
+        let mut unit_part = String::new();
+        while chars.peek().is_some_and(|c| !c.is_ascii_digit()) {
+            unit_part.push(chars.next().unwrap());
+        }
+        if unit_part.is_empty() {
+            return Err(format!("Missing unit in '{}'", adj));
+        }
+
+        let value: i64 = numeric_part
+            .parse()
+            .map_err(|e| format!("Invalid number: {}", e))?;
+
+        match unit_part.as_str() {
+            "s" => duration += chrono::Duration::seconds(value),
+            "m" => duration += chrono::Duration::minutes(value),
+            "h" => duration += chrono::Duration::hours(value),
+            "d" => duration += chrono::Duration::days(value),
+            "w" => duration += chrono::Duration::weeks(value),
+            "M" => {
+                let m = i32::try_from(value)
+                    .map_err(|_| format!("Month value out of range in '{}'", adj))?;
+                months = months
+                    .checked_add(m)
+                    .ok_or_else(|| format!("Overflow computing month adjustment in '{}'", adj))?;
+            }
+            "y" => {
+                let y = i32::try_from(value)
+                    .map_err(|_| format!("Year value out of range in '{}'", adj))?;
+                let y_months = y
+                    .checked_mul(12)
+                    .ok_or_else(|| format!("Overflow computing month adjustment in '{}'", adj))?;
+                months = months
+                    .checked_add(y_months)
+                    .ok_or_else(|| format!("Overflow computing month adjustment in '{}'", adj))?;
+            }
+            _ => return Err(format!("Unknown time unit '{}'. Use s (seconds), m (minutes), h (hours), d (days), w (weeks), M (months), or y (years)", unit_part)),
         }
     }
 
-    if numeric_part.is_empty() {
-        return Err(format!("Missing numeric part in '{}'", adj));
+    if is_negative {
+        months = -months;
+        duration = -duration;
     }
 
-    let value: i64 = numeric_part
-        .parse()
-        .map_err(|e| format!("Invalid number: {}", e))?;
-    let value = if is_negative { -value } else { value };
-
-    match unit_part.as_str() {
-        "s" => Ok(chrono::Duration::seconds(value)),
-        "m" => Ok(chrono::Duration::minutes(value)),
-        "h" => Ok(chrono::Duration::hours(value)),
-        "d" => Ok(chrono::Duration::days(value)),
-        "w" => Ok(chrono::Duration::weeks(value)),
-        _ => Err(format!("Unknown time unit '{}'. Use s (seconds), m (minutes), h (hours), d (days), or w (weeks)", unit_part)),
+    Ok(TimeAdjustment { months, duration })
+}
+
+/// Applies a (possibly negative) month offset to `datetime`, clamping to the
+/// last valid day of the resulting month (e.g. Jan 31 + 1 month -> Feb 28/29).
+fn apply_months(datetime: DateTime<Utc>, months: i32) -> Result<DateTime<Utc>, String> {
+    let date = datetime.date_naive();
+    let adjusted_date = if months >= 0 {
+        date.checked_add_months(chrono::Months::new(months as u32))
+    } else {
+        date.checked_sub_months(chrono::Months::new((-months) as u32))
     }
+    .ok_or_else(|| format!("Overflow applying {} month(s) to {}", months, date))?;
+
+    Ok(NaiveDateTime::new(adjusted_date, datetime.time()).and_utc())
 }
 
 #[derive(Parser)]
 #[command(name = "untd")]
 struct Args {
     timestamp: Option<i64>,
-    /// Timezone (e.g., "UTC", "JST")
+    /// Timezone: "UTC", "JST", or any IANA identifier (e.g., "Asia/Tokyo", "America/New_York")
     #[arg(short = 'z', long = "timezone", default_value = "JST")]
     timezone: String,
     /// Copy output to clipboard
     #[arg(short = 'c', long = "copy", default_value = "true")]
     copy: bool,
-    /// Output format (default: date only, "iso": ISO8601, "jp": Japanese date, "jpwd": Japanese date with weekday, "jphm": Japanese date with time, "jphms": Japanese date with time and seconds)
+    /// Output format (default: date only, "iso": ISO8601, "rfc2822": RFC 2822, "rfc3339": RFC 3339, "jp": Japanese date, "jpwd": Japanese date with localized weekday, "jphm": Japanese date with time, "jphms": Japanese date with time and seconds)
     #[arg(short = 'f', long = "format")]
     format: Option<String>,
-    /// Adjust time (e.g., "1m" adds 1 minute, "-30s" subtracts 30 seconds, "2d" adds 2 days)
-    /// Supported units: s (seconds), m (minutes), h (hours), d (days), w (weeks)
-    #[arg(short = 'a', long = "adjust")]
+    /// Adjust time (e.g., "1m" adds 1 minute, "-30s" subtracts 30 seconds, "2d" adds 2 days).
+    /// Units can be chained into a single expression, e.g. "1d12h30m" or "-1y2M15d".
+    /// Supported units: s (seconds), m (minutes), h (hours), d (days), w (weeks), M (months), y (years)
+    #[arg(short = 'a', long = "adjust", allow_hyphen_values = true)]
     adjust: Option<String>,
+    /// Parse a formatted date string (using the resolved --format) back into a Unix timestamp
+    #[arg(short = 'p', long = "parse")]
+    parse: Option<String>,
+    /// Locale for weekday/month names in %A/%a/%B/%b (e.g., "ja_JP", "en_US", "de_DE")
+    #[arg(short = 'l', long = "locale", default_value = "ja_JP")]
+    locale: String,
 }
 
 fn main() {
     let args: Args = Args::parse();
 
+    let tz = match resolve_timezone(&args.timezone) {
+        Ok(tz) => tz,
+        Err(e) => {
+            println!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let locale = match resolve_locale(&args.locale) {
+        Ok(locale) => locale,
+        Err(e) => {
+            println!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if let Some(date_str) = &args.parse {
+        let timestamp = match args.format.as_deref() {
+            // These carry their own offset, so they bypass `format_str`/timezone
+            // localization entirely, mirroring the output side in `format_datetime`.
+            Some("rfc2822") => DateTime::parse_from_rfc2822(date_str)
+                .map(|dt| dt.timestamp())
+                .map_err(|e| format!("Failed to parse '{}' as RFC 2822: {}", date_str, e)),
+            Some("rfc3339") => DateTime::parse_from_rfc3339(date_str)
+                .map(|dt| dt.timestamp())
+                .map_err(|e| format!("Failed to parse '{}' as RFC 3339: {}", date_str, e)),
+            format_option => {
+                // `%a`-based presets (like `jpwd`) have no locale-aware parsing
+                // counterpart to `format_localized`, so they get their own path too.
+                let naive = match format_option {
+                    Some("jpwd") => parse_jpwd_date(date_str),
+                    _ => parse_naive_datetime(date_str, get_format_string(format_option)),
+                };
+                naive.and_then(|naive| match naive.and_local_timezone(tz) {
+                    chrono::LocalResult::Single(dt) => Ok(dt.timestamp()),
+                    chrono::LocalResult::Ambiguous(_, _) => Err(format!(
+                        "'{}' is ambiguous in timezone '{}' (e.g. a DST fall-back overlap)",
+                        date_str, args.timezone
+                    )),
+                    chrono::LocalResult::None => Err(format!(
+                        "'{}' does not exist in timezone '{}' (e.g. a DST spring-forward gap)",
+                        date_str, args.timezone
+                    )),
+                })
+            }
+        };
+
+        match timestamp {
+            Ok(ts) => println!("{}", ts),
+            Err(e) => {
+                println!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     let mut datetime = if let Some(dt) = args.timestamp {
         match Utc.timestamp_opt(dt, 0) {
             chrono::LocalResult::Single(dt) => dt,
@@ -192,8 +528,17 @@ fn main() {
     // Apply time adjustment if specified
     if let Some(adj_str) = &args.adjust {
         match parse_time_adjustment(adj_str) {
-            Ok(duration) => {
-                datetime = datetime + duration;
+            Ok(adjustment) => {
+                if adjustment.months != 0 {
+                    datetime = match apply_months(datetime, adjustment.months) {
+                        Ok(dt) => dt,
+                        Err(e) => {
+                            println!("Error in time adjustment: {}", e);
+                            std::process::exit(1);
+                        }
+                    };
+                }
+                datetime += adjustment.duration;
             }
             Err(e) => {
                 println!("Error in time adjustment: {}", e);
@@ -202,44 +547,14 @@ fn main() {
         }
     }
 
-    let tz = match args.timezone.as_str() {
-        "UTC" => chrono_tz::UTC,
-        "JST" => chrono_tz::Asia::Tokyo,
-        _ => {
-            println!("Invalid timezone");
-            std::process::exit(1);
-        }
-    };
     let specific_datetime = datetime.with_timezone(&tz);
 
-    let format_str = get_format_string(args.format.as_deref());
-
-    let formatted = specific_datetime.format(format_str).to_string();
-
-    // Special handling for Japanese weekday format
-    let output = if args.format.as_deref() == Some("jpwd") {
-        // Replace the %w placeholder with the Japanese weekday character
-        formatted
-            .chars()
-            .enumerate()
-            .fold(String::new(), |mut result, (i, c)| {
-                if i > 0
-                    && formatted.chars().nth(i - 1) == Some('(')
-                    && c.is_digit(10)
-                    && i + 1 < formatted.len()
-                    && formatted.chars().nth(i + 1) == Some(')')
-                {
-                    result.push_str(get_japanese_weekday(c));
-                } else if !(i > 0
-                    && formatted.chars().nth(i - 1) == Some('(')
-                    && formatted.chars().nth(i) == Some(')'))
-                {
-                    result.push(c);
-                }
-                result
-            })
-    } else {
-        formatted
+    let output = match format_datetime(specific_datetime, args.format.as_deref(), locale) {
+        Ok(output) => output,
+        Err(e) => {
+            println!("{}", e);
+            std::process::exit(1);
+        }
     };
 
     println!("{}", output);